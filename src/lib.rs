@@ -7,6 +7,12 @@
 //! range which is basically the same as the [std::ops::RangeInclusive::contains()]
 //! method but extends it with the inverse behaviour.
 //!
+//! # Features
+//!
+//! * `serde`: implements `Serialize` and `Deserialize` for
+//!   [NagiosRange], (de)serializing it as the canonical string produced
+//!   by its [std::fmt::Display] impl.
+//!
 //! # Examples
 //!
 //! Create a `NagiosRange` from a literal string.
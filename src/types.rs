@@ -1,5 +1,7 @@
 use crate::error::Error;
 use std::fmt;
+use std::ops::{Bound, RangeBounds, RangeFrom, RangeInclusive, RangeToInclusive};
+use std::str::FromStr;
 
 /// A parsed Nagios range built from a literal string.
 /// A Nagios range works similar to [std::ops::RangeInclusive]
@@ -13,6 +15,10 @@ pub struct NagiosRange {
     pub(crate) check_type: CheckType,
     pub(crate) start: f64,
     pub(crate) end: f64,
+    /// Tracks whether `end` is excluded from the range. This only
+    /// happens when the range was parsed from Rust-style `a..b` syntax;
+    /// the Nagios colon syntax is always fully inclusive.
+    pub(crate) end_exclusive: bool,
 }
 
 impl NagiosRange {
@@ -37,12 +43,13 @@ impl NagiosRange {
             None => (CheckType::Outside, input),
         };
 
-        let (start, end) = parse_range(input)?;
+        let (start, end, end_exclusive) = parse_range(input)?;
 
         let range = NagiosRange {
             check_type,
             start,
             end,
+            end_exclusive,
         };
 
         Ok(range)
@@ -70,6 +77,7 @@ impl NagiosRange {
             check_type,
             start,
             end,
+            end_exclusive: false,
         })
     }
 
@@ -155,7 +163,11 @@ impl NagiosRange {
     /// }
     /// ```
     pub fn contains(&self, item: f64) -> bool {
-        item >= self.start && item <= self.end
+        if self.end_exclusive {
+            item >= self.start && item < self.end
+        } else {
+            item >= self.start && item <= self.end
+        }
     }
 
     /// Returns `true` if a value is either inside or outside
@@ -177,8 +189,8 @@ impl NagiosRange {
     /// ```
     pub fn check(&self, item: f64) -> bool {
         match self.check_type {
-            CheckType::Inside => item >= self.start && item <= self.end,
-            CheckType::Outside => item < self.start || item > self.end,
+            CheckType::Inside => self.contains(item),
+            CheckType::Outside => !self.contains(item),
         }
     }
 
@@ -234,6 +246,141 @@ impl NagiosRange {
     pub fn into_inner(self) -> (CheckType, f64, f64) {
         (self.check_type, self.start, self.end)
     }
+
+    /// Returns `true` if the range contains no values, i.e. the lower
+    /// bound is greater than the upper bound, or the bounds are equal
+    /// and the upper bound is excluded (a zero-width `a..a` range).
+    /// Mirrors [std::ops::RangeInclusive::is_empty()].
+    ///
+    /// ```rust
+    /// use nagios_range::NagiosRange;
+    ///
+    /// fn main() -> Result<(), nagios_range::Error> {
+    ///     let range = NagiosRange::from("10:10")?;
+    ///     assert!(!range.is_empty());
+    ///
+    ///     let range = NagiosRange::from("10..10")?;
+    ///     assert!(range.is_empty());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end || (self.end_exclusive && self.start == self.end)
+    }
+
+    /// Returns `true` if the underlying `[start, end]` interval of
+    /// `self` shares any point with the interval of `other`, regardless
+    /// of either range's [CheckType].
+    ///
+    /// ```rust
+    /// use nagios_range::NagiosRange;
+    ///
+    /// fn main() -> Result<(), nagios_range::Error> {
+    ///     let a = NagiosRange::from("0:10")?;
+    ///     let b = NagiosRange::from("5:15")?;
+    ///     assert!(a.overlaps(&b));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn overlaps(&self, other: &NagiosRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Computes the intersection of the underlying intervals of `self`
+    /// and `other`, returning `None` if they do not overlap. The
+    /// resulting range checks [CheckType::Inside] only if both `self`
+    /// and `other` do, otherwise it checks [CheckType::Outside]. The
+    /// upper bound is exclusive if it is contributed by an operand
+    /// whose own upper bound is exclusive (the tighter of the two wins
+    /// when both ends are equal).
+    ///
+    /// ```rust
+    /// use nagios_range::NagiosRange;
+    ///
+    /// fn main() -> Result<(), nagios_range::Error> {
+    ///     let a = NagiosRange::from("0:10")?;
+    ///     let b = NagiosRange::from("5:15")?;
+    ///     let result = a.intersection(&b).unwrap();
+    ///     assert_eq!(result, NagiosRange::from("5:10")?);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn intersection(&self, other: &NagiosRange) -> Option<NagiosRange> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let check_type = if self.checks_inside() && other.checks_inside() {
+            CheckType::Inside
+        } else {
+            CheckType::Outside
+        };
+
+        let end = self.end.min(other.end);
+        let end_exclusive = if self.end < other.end {
+            self.end_exclusive
+        } else if other.end < self.end {
+            other.end_exclusive
+        } else {
+            self.end_exclusive || other.end_exclusive
+        };
+
+        Some(NagiosRange {
+            check_type,
+            start: self.start.max(other.start),
+            end,
+            end_exclusive,
+        })
+    }
+
+    /// Computes the union of the underlying intervals of `self` and
+    /// `other`, returning `None` if they are disjoint, since a single
+    /// contiguous Nagios range cannot represent a gap. The resulting
+    /// range checks [CheckType::Inside] only if both `self` and `other`
+    /// do, otherwise it checks [CheckType::Outside]. The upper bound is
+    /// exclusive if it is contributed by an operand whose own upper
+    /// bound is exclusive (the looser of the two wins when both ends
+    /// are equal, so the upper bound is only exclusive if both operands
+    /// exclude it).
+    ///
+    /// ```rust
+    /// use nagios_range::NagiosRange;
+    ///
+    /// fn main() -> Result<(), nagios_range::Error> {
+    ///     let a = NagiosRange::from("0:10")?;
+    ///     let b = NagiosRange::from("5:15")?;
+    ///     let result = a.union(&b).unwrap();
+    ///     assert_eq!(result, NagiosRange::from("0:15")?);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn union(&self, other: &NagiosRange) -> Option<NagiosRange> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let check_type = if self.checks_inside() && other.checks_inside() {
+            CheckType::Inside
+        } else {
+            CheckType::Outside
+        };
+
+        let end = self.end.max(other.end);
+        let end_exclusive = if self.end > other.end {
+            self.end_exclusive
+        } else if other.end > self.end {
+            other.end_exclusive
+        } else {
+            self.end_exclusive && other.end_exclusive
+        };
+
+        Some(NagiosRange {
+            check_type,
+            start: self.start.min(other.start),
+            end,
+            end_exclusive,
+        })
+    }
 }
 
 impl fmt::Display for NagiosRange {
@@ -255,6 +402,156 @@ impl fmt::Display for NagiosRange {
     }
 }
 
+/// Builds an "outside" [NagiosRange] (the Nagios default when no `@`
+/// prefix is present) from a [`RangeInclusive<f64>`].
+///
+/// ```rust
+/// use nagios_range::NagiosRange;
+///
+/// let range: NagiosRange = (0.0..=10.0).into();
+/// assert_eq!(range, NagiosRange::from("0:10").unwrap());
+/// ```
+impl From<RangeInclusive<f64>> for NagiosRange {
+    fn from(range: RangeInclusive<f64>) -> Self {
+        NagiosRange {
+            check_type: CheckType::Outside,
+            start: *range.start(),
+            end: *range.end(),
+            end_exclusive: false,
+        }
+    }
+}
+
+/// Builds an "outside" [NagiosRange] from a [`RangeToInclusive<f64>`],
+/// mapping the open lower bound to [f64::NEG_INFINITY].
+///
+/// ```rust
+/// use nagios_range::NagiosRange;
+///
+/// let range: NagiosRange = (..=10.0).into();
+/// assert_eq!(range, NagiosRange::from("~:10").unwrap());
+/// ```
+impl From<RangeToInclusive<f64>> for NagiosRange {
+    fn from(range: RangeToInclusive<f64>) -> Self {
+        NagiosRange {
+            check_type: CheckType::Outside,
+            start: f64::NEG_INFINITY,
+            end: range.end,
+            end_exclusive: false,
+        }
+    }
+}
+
+/// Builds an "outside" [NagiosRange] from a [`RangeFrom<f64>`], mapping
+/// the open upper bound to [f64::INFINITY].
+///
+/// ```rust
+/// use nagios_range::NagiosRange;
+///
+/// let range: NagiosRange = (10.0..).into();
+/// assert_eq!(range, NagiosRange::from("10:").unwrap());
+/// ```
+impl From<RangeFrom<f64>> for NagiosRange {
+    fn from(range: RangeFrom<f64>) -> Self {
+        NagiosRange {
+            check_type: CheckType::Outside,
+            start: range.start,
+            end: f64::INFINITY,
+            end_exclusive: false,
+        }
+    }
+}
+
+/// Delegates to [NagiosRange::from()].
+impl TryFrom<&str> for NagiosRange {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        NagiosRange::from(input)
+    }
+}
+
+/// Allows parsing a [NagiosRange] via [str::parse()].
+///
+/// ```rust
+/// use nagios_range::NagiosRange;
+///
+/// let range: NagiosRange = "@0:10".parse().unwrap();
+/// assert_eq!(range, NagiosRange::from("@0:10").unwrap());
+/// ```
+impl FromStr for NagiosRange {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        NagiosRange::from(input)
+    }
+}
+
+/// Lets a [NagiosRange] slot into generic code written against
+/// [std::ops::RangeBounds], reusing the same infinity-aware logic as
+/// the [fmt::Display] impl and the `start_is_infinite`/`end_is_infinite`
+/// helpers. Note that this trait's `contains()` is always
+/// inclusive/exclusive based on the bounds below and does not invert
+/// for [CheckType::Outside] ranges the way [NagiosRange::check()] does;
+/// since [NagiosRange] already has an inherent `contains()` method, call
+/// the trait one via [RangeBounds::contains()] as shown below.
+///
+/// ```rust
+/// use nagios_range::NagiosRange;
+/// use std::ops::RangeBounds;
+///
+/// fn main() -> Result<(), nagios_range::Error> {
+///     let range = NagiosRange::from("@0:10")?;
+///     assert!(RangeBounds::contains(&range, &5.0));
+///     assert!(!RangeBounds::contains(&range, &20.0));
+///     Ok(())
+/// }
+/// ```
+impl RangeBounds<f64> for NagiosRange {
+    fn start_bound(&self) -> Bound<&f64> {
+        if self.start_is_infinite() {
+            Bound::Unbounded
+        } else {
+            Bound::Included(&self.start)
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&f64> {
+        if self.end_is_infinite() {
+            Bound::Unbounded
+        } else if self.end_exclusive {
+            Bound::Excluded(&self.end)
+        } else {
+            Bound::Included(&self.end)
+        }
+    }
+}
+
+/// Serializes a [NagiosRange] as the canonical string produced by its
+/// [fmt::Display] impl, e.g. `"@0:10"`. Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl serde::Serialize for NagiosRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes a [NagiosRange] from the canonical string form, routing
+/// it through [NagiosRange::from()]. Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NagiosRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        NagiosRange::from(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 /// This enum indicates if [NagiosRange::check()] should
 /// check if a value lies inside or outside of the range.
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -263,7 +560,11 @@ pub enum CheckType {
     Outside,
 }
 
-fn parse_range(range: &str) -> Result<(f64, f64), Error> {
+fn parse_range(range: &str) -> Result<(f64, f64, bool), Error> {
+    if range.contains("..") {
+        return parse_rust_range(range);
+    }
+
     match range.split_once(':') {
         Some(parts) => {
             let start = if parts.0 == "~" {
@@ -285,16 +586,61 @@ fn parse_range(range: &str) -> Result<(f64, f64), Error> {
                 num
             };
 
-            Ok((start, end))
+            Ok((start, end, false))
         }
         None => {
             let start = 0.0;
             let end: f64 = range.parse().map_err(Error::ParseEndPoint)?;
-            Ok((start, end))
+            Ok((start, end, false))
         }
     }
 }
 
+/// Parses the Rust-style interval syntax (`a..b`, `a..=b`, `..b`, `a..`)
+/// accepted as an alternative to the Nagios colon syntax. Returns the
+/// lower bound, the upper bound, and whether the upper bound is
+/// exclusive (only the case for `a..b` and `..b`, since `a..=b` and
+/// `..=b` are inclusive like the rest of the Nagios range format).
+fn parse_rust_range(range: &str) -> Result<(f64, f64, bool), Error> {
+    if let Some(end) = range.strip_prefix("..=") {
+        let end: f64 = end.parse().map_err(Error::ParseEndPoint)?;
+        return Ok((f64::NEG_INFINITY, end, false));
+    }
+
+    if let Some(end) = range.strip_prefix("..") {
+        let end: f64 = end.parse().map_err(Error::ParseEndPoint)?;
+        return Ok((f64::NEG_INFINITY, end, true));
+    }
+
+    if let Some((start, end)) = range.split_once("..=") {
+        let start: f64 = start.parse().map_err(Error::ParseStartPoint)?;
+        let end: f64 = end.parse().map_err(Error::ParseEndPoint)?;
+
+        if start > end {
+            return Err(Error::StartGreaterThanEnd);
+        }
+
+        return Ok((start, end, false));
+    }
+
+    let (start, end) = range
+        .split_once("..")
+        .expect("caller already checked that the range contains \"..\"");
+    let start: f64 = start.parse().map_err(Error::ParseStartPoint)?;
+
+    if end.is_empty() {
+        return Ok((start, f64::INFINITY, false));
+    }
+
+    let end: f64 = end.parse().map_err(Error::ParseEndPoint)?;
+
+    if start > end {
+        return Err(Error::StartGreaterThanEnd);
+    }
+
+    Ok((start, end, true))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::error::Error;
@@ -307,6 +653,7 @@ mod tests {
             check_type: CheckType::Outside,
             start: 0.0,
             end: 10.0,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -318,6 +665,7 @@ mod tests {
             check_type: CheckType::Outside,
             start: 10.0,
             end: f64::INFINITY,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -329,6 +677,7 @@ mod tests {
             check_type: CheckType::Outside,
             start: 0.0,
             end: 10.0,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -340,6 +689,7 @@ mod tests {
             check_type: CheckType::Outside,
             start: f64::NEG_INFINITY,
             end: 10.0,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -351,6 +701,7 @@ mod tests {
             check_type: CheckType::Outside,
             start: 10.0,
             end: 20.0,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -362,6 +713,7 @@ mod tests {
             check_type: CheckType::Inside,
             start: 10.0,
             end: 20.0,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -373,6 +725,7 @@ mod tests {
             check_type: CheckType::Inside,
             start: -10.0,
             end: 20.0,
+            end_exclusive: false,
         };
         assert_eq!(result, Ok(expect));
     }
@@ -425,4 +778,301 @@ mod tests {
         let result = "~:10".to_string();
         assert_eq!(range.to_string(), result);
     }
+
+    #[test]
+    fn from_range_inclusive() {
+        let result: NagiosRange = (0.0..=10.0).into();
+        let expect = NagiosRange::from("0:10").unwrap();
+        assert_eq!(result, expect);
+    }
+
+    #[test]
+    fn from_range_to_inclusive() {
+        let result: NagiosRange = (..=10.0).into();
+        let expect = NagiosRange::from("~:10").unwrap();
+        assert_eq!(result, expect);
+    }
+
+    #[test]
+    fn from_range_from() {
+        let result: NagiosRange = (10.0..).into();
+        let expect = NagiosRange::from("10:").unwrap();
+        assert_eq!(result, expect);
+    }
+
+    #[test]
+    fn try_from_str() {
+        let result = NagiosRange::try_from("@0:10").unwrap();
+        let expect = NagiosRange::from("@0:10").unwrap();
+        assert_eq!(result, expect);
+    }
+
+    #[test]
+    fn from_str_parse() {
+        let result: NagiosRange = "@0:10".parse().unwrap();
+        let expect = NagiosRange::from("@0:10").unwrap();
+        assert_eq!(result, expect);
+    }
+
+    #[test]
+    fn is_empty_false() {
+        let range = NagiosRange::from("0:10").unwrap();
+        assert!(!range.is_empty());
+    }
+
+    #[test]
+    fn is_empty_zero_width_exclusive() {
+        let range = NagiosRange::from("10..10").unwrap();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn is_empty_zero_width_inclusive() {
+        let range = NagiosRange::from("10..=10").unwrap();
+        assert!(!range.is_empty());
+    }
+
+    #[test]
+    fn overlaps_true() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("5:15").unwrap();
+        assert!(a.overlaps(&b));
+    }
+
+    #[test]
+    fn overlaps_false() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("20:30").unwrap();
+        assert!(!a.overlaps(&b));
+    }
+
+    #[test]
+    fn intersection_overlapping() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("5:15").unwrap();
+        let result = a.intersection(&b);
+        let expect = NagiosRange::from("5:10").unwrap();
+        assert_eq!(result, Some(expect));
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("20:30").unwrap();
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn intersection_check_type() {
+        let a = NagiosRange::from("@0:10").unwrap();
+        let b = NagiosRange::from("5:15").unwrap();
+        let result = a.intersection(&b).unwrap();
+        assert_eq!(result.check_type, CheckType::Outside);
+    }
+
+    #[test]
+    fn intersection_propagates_exclusivity_from_tighter_end() {
+        let a = NagiosRange::from("0..10").unwrap();
+        let b = NagiosRange::from("5:15").unwrap();
+        let result = a.intersection(&b).unwrap();
+        assert_eq!(result.end, 10.0);
+        assert!(result.end_exclusive);
+        assert!(!result.contains(10.0));
+    }
+
+    #[test]
+    fn intersection_equal_ends_exclusive_wins() {
+        let a = NagiosRange::from("0..10").unwrap();
+        let b = NagiosRange::from("5..=10").unwrap();
+        let result = a.intersection(&b).unwrap();
+        assert_eq!(result.end, 10.0);
+        assert!(result.end_exclusive);
+    }
+
+    #[test]
+    fn union_overlapping() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("5:15").unwrap();
+        let result = a.union(&b);
+        let expect = NagiosRange::from("0:15").unwrap();
+        assert_eq!(result, Some(expect));
+    }
+
+    #[test]
+    fn union_disjoint() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("20:30").unwrap();
+        assert_eq!(a.union(&b), None);
+    }
+
+    #[test]
+    fn union_propagates_exclusivity_from_looser_end() {
+        let a = NagiosRange::from("0:10").unwrap();
+        let b = NagiosRange::from("5..15").unwrap();
+        let result = a.union(&b).unwrap();
+        assert_eq!(result.end, 15.0);
+        assert!(result.end_exclusive);
+    }
+
+    #[test]
+    fn union_equal_ends_both_exclusive() {
+        let a = NagiosRange::from("0..10").unwrap();
+        let b = NagiosRange::from("5..10").unwrap();
+        let result = a.union(&b).unwrap();
+        assert_eq!(result.end, 10.0);
+        assert!(result.end_exclusive);
+    }
+
+    #[test]
+    fn union_equal_ends_one_inclusive() {
+        let a = NagiosRange::from("0..10").unwrap();
+        let b = NagiosRange::from("5..=10").unwrap();
+        let result = a.union(&b).unwrap();
+        assert_eq!(result.end, 10.0);
+        assert!(!result.end_exclusive);
+    }
+
+    #[test]
+    fn parse_rust_range_inclusive() {
+        let result = NagiosRange::from("-10..=10");
+        let expect = NagiosRange {
+            check_type: CheckType::Outside,
+            start: -10.0,
+            end: 10.0,
+            end_exclusive: false,
+        };
+        assert_eq!(result, Ok(expect));
+    }
+
+    #[test]
+    fn parse_rust_range_exclusive() {
+        let result = NagiosRange::from("-10..10");
+        let expect = NagiosRange {
+            check_type: CheckType::Outside,
+            start: -10.0,
+            end: 10.0,
+            end_exclusive: true,
+        };
+        assert_eq!(result, Ok(expect));
+    }
+
+    #[test]
+    fn parse_rust_range_to_inclusive() {
+        let result = NagiosRange::from("..=10");
+        let expect = NagiosRange {
+            check_type: CheckType::Outside,
+            start: f64::NEG_INFINITY,
+            end: 10.0,
+            end_exclusive: false,
+        };
+        assert_eq!(result, Ok(expect));
+    }
+
+    #[test]
+    fn parse_rust_range_to_exclusive() {
+        let result = NagiosRange::from("..10");
+        let expect = NagiosRange {
+            check_type: CheckType::Outside,
+            start: f64::NEG_INFINITY,
+            end: 10.0,
+            end_exclusive: true,
+        };
+        assert_eq!(result, Ok(expect));
+    }
+
+    #[test]
+    fn parse_rust_range_from() {
+        let result = NagiosRange::from("10..");
+        let expect = NagiosRange {
+            check_type: CheckType::Outside,
+            start: 10.0,
+            end: f64::INFINITY,
+            end_exclusive: false,
+        };
+        assert_eq!(result, Ok(expect));
+    }
+
+    #[test]
+    fn parse_rust_range_inside() {
+        let result = NagiosRange::from("@0..=10");
+        let expect = NagiosRange {
+            check_type: CheckType::Inside,
+            start: 0.0,
+            end: 10.0,
+            end_exclusive: false,
+        };
+        assert_eq!(result, Ok(expect));
+    }
+
+    #[test]
+    fn parse_rust_range_start_greater_than_end() {
+        let result = NagiosRange::from("10..5");
+        let expect = Error::StartGreaterThanEnd;
+        assert_eq!(result, Err(expect));
+    }
+
+    #[test]
+    fn check_exclusive_upper_bound() {
+        let range = NagiosRange::from("0..10").unwrap();
+        assert!(range.contains(9.999));
+        assert!(!range.contains(10.0));
+    }
+
+    #[test]
+    fn range_bounds_finite() {
+        use std::ops::{Bound, RangeBounds};
+
+        let range = NagiosRange::from("0:10").unwrap();
+        assert_eq!(range.start_bound(), Bound::Included(&0.0));
+        assert_eq!(range.end_bound(), Bound::Included(&10.0));
+    }
+
+    #[test]
+    fn range_bounds_infinite() {
+        use std::ops::{Bound, RangeBounds};
+
+        let range = NagiosRange::from("~:10").unwrap();
+        assert_eq!(range.start_bound(), Bound::Unbounded);
+        assert_eq!(range.end_bound(), Bound::Included(&10.0));
+    }
+
+    #[test]
+    fn range_bounds_exclusive_end() {
+        use std::ops::{Bound, RangeBounds};
+
+        let range = NagiosRange::from("0..10").unwrap();
+        assert_eq!(range.end_bound(), Bound::Excluded(&10.0));
+    }
+
+    #[test]
+    fn range_bounds_contains() {
+        use std::ops::RangeBounds;
+
+        let range = NagiosRange::from("@0:10").unwrap();
+        assert!(RangeBounds::contains(&range, &5.0));
+        assert!(!RangeBounds::contains(&range, &20.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_range() {
+        let range = NagiosRange::from("@0:10").unwrap();
+        let result = serde_json::to_string(&range).unwrap();
+        assert_eq!(result, "\"@0:10\"");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_range() {
+        let result: NagiosRange = serde_json::from_str("\"@0:10\"").unwrap();
+        let expect = NagiosRange::from("@0:10").unwrap();
+        assert_eq!(result, expect);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_range_invalid() {
+        let result: Result<NagiosRange, _> = serde_json::from_str("\"not a range\"");
+        assert!(result.is_err());
+    }
 }